@@ -2,11 +2,13 @@ use std::{time::Instant, vec};
 use std::sync::Arc;
 
 use crate::{Bar, Chart};
+use crate::indicators::{Indicator, Sma};
 
-use chrono::Datelike;
+use chrono::{Datelike, Month};
+use std::convert::TryFrom;
 
-use druid::{Color, Rect};
-use druid::piet::{FontFamily, RenderContext, Text, TextLayout, TextLayoutBuilder};
+use druid::{Color, Point, Rect};
+use druid::piet::{FontFamily, RenderContext, StrokeStyle, Text, TextLayout, TextLayoutBuilder};
 use druid::kurbo::Line;
 use druid::widget::prelude::*;
 
@@ -19,6 +21,21 @@ const Y_AXIS_LABELS_PADDING: f64 = 40.0;
 static Y_AXIS_TICK_INCREMENTS: &'static [f64] = &[0.1, 0.5, 1.0, 10.0, 100.0];
 const TEXT_COLOR: Color = Color::rgb8(0xef, 0xf8, 0xff);
 
+/// Minimum number of bars we'll ever zoom in to, so the chart doesn't
+/// collapse to nothing under the cursor.
+const MIN_BARS_PER_VIEW: usize = 5;
+
+/// How many bars zooming in/out adds or removes per scroll-wheel tick.
+const ZOOM_STEP: usize = 5;
+
+/// Fraction of the plot area (below the x-axis label padding) reserved for
+/// the volume histogram at the bottom, the rest goes to the price panel.
+const VOLUME_PANEL_HEIGHT_RATIO: f64 = 0.2;
+
+/// Approximate pixel width a date label needs, used to space the x-axis
+/// labels placed by stride out so they don't overlap.
+const X_AXIS_LABEL_WIDTH: f64 = 55.0;
+
 struct PriceRange {
     lowest: f64,
     highest: f64
@@ -30,45 +47,170 @@ impl PriceRange {
     }
 }
 
+/// Width available for plotting bars, i.e. the widget minus the axis
+/// padding reserved on either side.
+fn available_plot_width(size: Size) -> f64 {
+    (size.width - Y_AXIS_LABELS_PADDING - 2.0 * BAR_SPACING as f64).max(1.0)
+}
+
+/// Pixel pitch (candle width + spacing) for one bar. Starts at the default
+/// `BAR_WIDTH + BAR_SPACING` and shrinks as `bars_per_view` grows, so that
+/// zooming out actually compresses the view to fit `bars_per_view` bars
+/// instead of letting the extra ones spill past the right edge.
+fn bar_pitch(size: Size, bars_per_view: usize) -> f64 {
+    let fitted = available_plot_width(size) / bars_per_view.max(1) as f64;
+    fitted.min((BAR_WIDTH + BAR_SPACING) as f64)
+}
+
+/// Candle body width for a given pitch, keeping the same width:spacing
+/// ratio as the default `BAR_WIDTH`/`BAR_SPACING`.
+fn bar_width(pitch: f64) -> f64 {
+    pitch * BAR_WIDTH as f64 / (BAR_WIDTH + BAR_SPACING) as f64
+}
+
+/// Spacing between candles for a given pitch (the remainder after `bar_width`).
+fn bar_spacing(pitch: f64) -> f64 {
+    pitch - bar_width(pitch)
+}
+
+/// Computes the new `view_offset` after panning by `bar_delta` bars, clamped
+/// so the viewport never scrolls past either end of the data.
+fn clamp_pan(view_offset: usize, bar_delta: i32, bars_len: usize, bars_per_view: usize) -> usize {
+    let max_offset = bars_len.saturating_sub(bars_per_view) as i32;
+    (view_offset as i32 + bar_delta).clamp(0, max_offset.max(0)) as usize
+}
+
+/// Computes the new `(bars_per_view, view_offset)` for one zoom step,
+/// keeping the bar under the cursor (`cursor_ratio`, its position as a
+/// fraction of the old viewport width, measured from the left/oldest edge)
+/// in roughly the same screen spot. `view_offset` counts bars back from the
+/// newest (right edge), so a cursor near the left/oldest edge needs almost
+/// the *full* shift applied to `view_offset` and one near the right/newest
+/// edge needs almost none -- hence the `1.0 - cursor_ratio` weight below.
+fn clamp_zoom(
+    old_bars_per_view: usize,
+    old_view_offset: usize,
+    zooming_in: bool,
+    cursor_ratio: f64,
+    bars_len: usize
+) -> (usize, usize) {
+    let new_bars_per_view = if zooming_in {
+        old_bars_per_view.saturating_sub(ZOOM_STEP).max(MIN_BARS_PER_VIEW)
+    } else {
+        (old_bars_per_view + ZOOM_STEP).min(bars_len.max(MIN_BARS_PER_VIEW))
+    };
+
+    if new_bars_per_view == old_bars_per_view {
+        return (old_bars_per_view, old_view_offset);
+    }
+
+    let bars_added = new_bars_per_view as f64 - old_bars_per_view as f64;
+    let offset_shift = (bars_added * (1.0 - cursor_ratio)) as i32;
+
+    let max_offset = bars_len.saturating_sub(new_bars_per_view) as i32;
+    let new_offset = (old_view_offset as i32 - offset_shift).clamp(0, max_offset.max(0));
+
+    (new_bars_per_view, new_offset as usize)
+}
+
 pub struct ChartWidget {
     bars: Arc<Vec<Bar>>,
-    size: Size
+    view_offset: usize,
+    bars_per_view: usize,
+    size: Size,
+
+    /// Mouse position where the current left-drag started, in widget
+    /// coordinates. `None` when the mouse isn't being dragged.
+    drag_origin: Option<Point>,
+
+    /// Indicators currently overlaid on the price chart, each with the
+    /// color its line should be stroked in.
+    indicators: Vec<(Arc<dyn Indicator>, Color)>,
+
+    /// Last known mouse position, in widget coordinates. `None` when the
+    /// cursor isn't over the chart, which hides the crosshair.
+    cursor_pos: Option<Point>
 }
 
-struct AxisLabel {
+pub(crate) struct AxisLabel {
     /// Label text
-    label: String,
-    
+    pub(crate) label: String,
+
     /// Position where to draw the label
-    position: (f64, f64)
+    pub(crate) position: (f64, f64)
 }
 
-struct AxisTick {
+pub(crate) struct AxisTick {
     /// How many pixels from the start (0) in should we place the tick
-    tick_line: Line,
+    pub(crate) tick_line: Line,
 
     /// What is the label we should print with the tick
-    label: AxisLabel
+    pub(crate) label: AxisLabel
 }
 
-struct Candle {
-    wick: Line,
-    body: Rect,
-    color: Color
+pub(crate) struct Candle {
+    pub(crate) wick: Line,
+    pub(crate) body: Rect,
+    pub(crate) color: Color
+}
+
+pub(crate) struct VolumeBar {
+    pub(crate) body: Rect,
+    pub(crate) color: Color
+}
+
+struct Crosshair {
+    vertical: Line,
+    horizontal: Line,
+
+    /// Price at the cursor's height, placed next to the y-axis.
+    price_label: AxisLabel,
+
+    /// The hovered bar's date and O/H/L/C, placed near the cursor.
+    tooltip: AxisLabel
 }
 
 impl ChartWidget {
     pub fn new(chart: &Chart, size: Size) -> Self {
         Self {
             bars: chart.clone().bars,
-            size: size
+            view_offset: chart.view_offset,
+            bars_per_view: chart.bars_per_view,
+            size: size,
+            drag_origin: None,
+            indicators: vec![],
+            cursor_pos: None
         }
     }
 
     pub fn empty() -> Self {
-        Self {
+        let mut widget = Self {
             bars: Arc::new(vec![]),
-            size: Size::new(0.0, 0.0)
+            view_offset: 0,
+            bars_per_view: 0,
+            size: Size::new(0.0, 0.0),
+            drag_origin: None,
+            indicators: vec![],
+            cursor_pos: None
+        };
+
+        // Show a 20-period SMA by default so the overlay subsystem is
+        // actually reachable without the caller having to know about it.
+        widget.add_indicator(Arc::new(Sma { period: 20 }), Color::rgb8(0xf2, 0xc1, 0x4e));
+
+        widget
+    }
+
+    /// Adds an indicator overlay, drawn in the given color.
+    pub fn add_indicator(&mut self, indicator: Arc<dyn Indicator>, color: Color) {
+        self.indicators.push((indicator, color));
+    }
+
+    /// Removes the indicator previously added at `index` (the order they
+    /// were added in), if it's still there.
+    pub fn remove_indicator(&mut self, index: usize) {
+        if index < self.indicators.len() {
+            self.indicators.remove(index);
         }
     }
 
@@ -76,8 +218,8 @@ impl ChartWidget {
     fn price_range(&self, bars: Vec<&Bar>) -> PriceRange {
         // TODO: let's try to figure out how to merge this pass with the one below in
         // `visible_bars` so we don't do 2 passes
-        let mut max_price: f64 = self.bars.first().unwrap().high;
-        let mut min_price: f64 = self.bars.first().unwrap().low;
+        let mut max_price: f64 = bars.first().unwrap().high;
+        let mut min_price: f64 = bars.first().unwrap().low;
 
         for bar in bars.iter() {
             if bar.high > max_price {
@@ -90,20 +232,14 @@ impl ChartWidget {
         PriceRange { lowest: min_price, highest: max_price }
     }
 
-    /// Returns a list of bars that will be visible on the chart given the chart
-    /// size and padding settings
+    /// Returns the bars visible in the current viewport, i.e. `[view_offset,
+    /// view_offset + bars_per_view)` counting back from the most recent bar,
+    /// oldest first so callers can plot left-to-right.
     fn visible_bars(&self) -> Vec<&Bar> {
-        let mut bars_to_render: Vec<&Bar> = vec![];
-
-        // TODO: implement rendering charts that don't have the last price on the right edge, but
-        // have a custom range.
-        for bar in self.bars.iter() {
-            // Let's only plot bars that we can fit into the available screen area
-            if bars_to_render.len() as i32 * (BAR_WIDTH + BAR_SPACING) > (self.size.width - Y_AXIS_LABELS_PADDING - 2.0 * BAR_SPACING as f64) as i32 {
-                break;
-            }
-            bars_to_render.push(bar);
-        }
+        let start = self.view_offset.min(self.bars.len());
+        let end = (start + self.bars_per_view).min(self.bars.len());
+
+        let mut bars_to_render: Vec<&Bar> = self.bars[start..end].iter().collect();
 
         println!("Rendering bars from {:?} to {:?}", bars_to_render.first(), bars_to_render.last());
 
@@ -111,21 +247,32 @@ impl ChartWidget {
         bars_to_render
     }
 
-    fn x_axis(&self) -> Line {
+    /// Height in pixels of the price panel, i.e. the plot area above the
+    /// x-axis labels minus the volume panel reserved at the bottom.
+    fn price_panel_height(&self) -> f64 {
+        (self.size.height - X_AXIS_LABELS_PADDING) * (1.0 - VOLUME_PANEL_HEIGHT_RATIO)
+    }
+
+    /// Height in pixels of the volume panel at the bottom of the plot area.
+    fn volume_panel_height(&self) -> f64 {
+        (self.size.height - X_AXIS_LABELS_PADDING) * VOLUME_PANEL_HEIGHT_RATIO
+    }
+
+    pub(crate) fn x_axis(&self) -> Line {
         Line::new(
             (BAR_SPACING as f64, self.size.height - X_AXIS_LABELS_PADDING),
             (self.size.width - Y_AXIS_LABELS_PADDING, self.size.height - X_AXIS_LABELS_PADDING)
         )
     }
 
-    fn y_axis(&self) -> Line {
+    pub(crate) fn y_axis(&self) -> Line {
         Line::new(
             (self.size.width - Y_AXIS_LABELS_PADDING, BAR_SPACING as f64),
             (self.size.width - Y_AXIS_LABELS_PADDING, self.size.height - BAR_SPACING as f64)
         )
     }
 
-    fn y_axis_ticks(&self) -> Vec<AxisTick> {
+    pub(crate) fn y_axis_ticks(&self) -> Vec<AxisTick> {
         let price_range = self.price_range(self.visible_bars());
         let approx_num_of_ticks = self.size.height / Y_TICK_SPACING;
         let mut closest_tick_size = Y_AXIS_TICK_INCREMENTS[0];
@@ -138,7 +285,7 @@ impl ChartWidget {
             }
         }
 
-        let scaling: f64 = (self.size.height - X_AXIS_LABELS_PADDING) / price_range.range();
+        let scaling: f64 = self.price_panel_height() / price_range.range();
         let y_tick_start = price_range.highest % closest_tick_size;
         let mut current_y_tick = y_tick_start;
         let mut ticks: Vec<AxisTick> = vec![];
@@ -164,31 +311,84 @@ impl ChartWidget {
         ticks
     }
 
+    /// Returns the x-axis date labels to draw. A label is placed every
+    /// `stride` bars, the stride being the fewest bars that span
+    /// `X_AXIS_LABEL_WIDTH` at the current bar pitch, so these labels don't
+    /// overlap. A label is also placed whenever a new month starts (and a new
+    /// week too, once the stride is 7 bars or fewer) whatever its distance
+    /// from the previous label, so a boundary label can overlap a neighbour.
+    /// Positions are clamped so that a label of `X_AXIS_LABEL_WIDTH` can't
+    /// draw past the chart's edges.
+    pub(crate) fn x_axis_labels(&self) -> Vec<AxisLabel> {
+        let bars = self.visible_bars();
+        if bars.is_empty() {
+            return vec![];
+        }
+
+        let pitch = bar_pitch(self.size, self.bars_per_view);
+        let stride = ((X_AXIS_LABEL_WIDTH / pitch).ceil() as usize).max(1);
+
+        let min_x = BAR_SPACING as f64;
+        let max_x = (self.size.width - Y_AXIS_LABELS_PADDING - X_AXIS_LABEL_WIDTH).max(min_x);
+
+        let mut labels: Vec<AxisLabel> = vec![];
+        let mut x_position: f64 = bar_spacing(pitch) * 2.0;
+        let mut last_labelled_index: Option<usize> = None;
+
+        for (i, bar) in bars.iter().enumerate() {
+            let previous_date = if i == 0 { None } else { Some(bars[i - 1].date.clone()) };
+            let new_month = previous_date.as_ref().map_or(true, |prev| prev.month() != bar.date.month());
+            let new_week = previous_date.as_ref().map_or(true, |prev| prev.iso_week() != bar.date.iso_week());
+            let due_by_stride = last_labelled_index.map_or(true, |last| i - last >= stride);
+
+            if due_by_stride || new_month || (stride <= 7 && new_week) {
+                let text = if new_month {
+                    let month = Month::try_from(bar.date.month() as u8).unwrap();
+                    format!("{} {}", month.name(), bar.date.year())
+                } else {
+                    bar.date.day().to_string()
+                };
+
+                labels.push(AxisLabel {
+                    label: text,
+                    position: (x_position.clamp(min_x, max_x), self.size.height - X_AXIS_LABELS_PADDING + 5.0)
+                });
+                last_labelled_index = Some(i);
+            }
+
+            x_position += pitch;
+        }
+
+        labels
+    }
+
     /// Returns candles to be plotted
-    fn candles(&self) -> Vec<Candle> {
+    pub(crate) fn candles(&self) -> Vec<Candle> {
         // Now let's plot the candle body
         let price_range = self.price_range(self.visible_bars());
-        let scaling: f64 = (self.size.height - X_AXIS_LABELS_PADDING) / price_range.range();
-        let mut x_position: i32 = BAR_SPACING * 2; // Let's leave some padding to the left
+        let scaling: f64 = self.price_panel_height() / price_range.range();
+        let pitch = bar_pitch(self.size, self.bars_per_view);
+        let width = bar_width(pitch);
+        let mut x_position: f64 = bar_spacing(pitch) * 2.0; // Let's leave some padding to the left
         let mut candles: Vec<Candle> = vec![];
 
         for bar in self.visible_bars() {
             // Wick
             let bar_high = (price_range.highest - bar.high) * scaling;
             let bar_low = (price_range.highest - bar.low) * scaling;
-            let wick = Line::new((x_position as f64, bar_high as f64), (x_position as f64, bar_low as f64));
+            let wick = Line::new((x_position, bar_high), (x_position, bar_low));
 
             // Candle body
             let higher_value = if bar.close > bar.open { bar.close } else { bar.open };
             let bar_y_top: f64 = (price_range.highest - higher_value) * scaling;
 
-            let bar_start = ((x_position - BAR_WIDTH/2) as f64, bar_y_top);
+            let bar_start = (x_position - width / 2.0, bar_y_top);
 
             let lower_value = if bar.close > bar.open { bar.open } else { bar.close };
             let bar_height = ((price_range.highest - lower_value) * scaling) - bar_y_top;
 
             // from_origin_size means it starts at (10,10) and is 100 wide and 100 tall
-            let bar_rect = Rect::from_origin_size(bar_start, (BAR_WIDTH as f64, bar_height as f64));
+            let bar_rect = Rect::from_origin_size(bar_start, (width, bar_height));
 
             // Color
             let fill_color = if higher_value == bar.close {
@@ -198,7 +398,7 @@ impl ChartWidget {
                 Color::rgb8(0xdc, 0x30, 0x30)
             };
 
-            x_position += BAR_WIDTH + BAR_SPACING;
+            x_position += pitch;
 
             candles.push(Candle {
                 wick: wick,
@@ -209,19 +409,205 @@ impl ChartWidget {
 
         candles
     }
+
+    /// Returns one polyline (and its stroke color) per active indicator,
+    /// built from values aligned to the currently visible bars. A `None`
+    /// value (warm-up, or a gap) breaks the line instead of connecting
+    /// across it.
+    pub(crate) fn indicator_lines(&self) -> Vec<(Vec<Line>, Color)> {
+        let price_range = self.price_range(self.visible_bars());
+        let scaling: f64 = self.price_panel_height() / price_range.range();
+        let pitch = bar_pitch(self.size, self.bars_per_view);
+
+        let start = self.view_offset.min(self.bars.len());
+        let end = (start + self.bars_per_view).min(self.bars.len());
+
+        // `Indicator::series` expects oldest-first input, but `self.bars` is
+        // newest-first (the SQL query is `ORDER BY ap.timestamp DESC`). Flip
+        // to chronological order to compute, then flip back so the series
+        // lines up index-for-index with `self.bars` again.
+        let chronological_bars: Vec<Bar> = self.bars.iter().rev().cloned().collect();
+
+        self.indicators.iter().map(|(indicator, color)| {
+            // Compute over the full history so warm-up periods and EMA state
+            // line up correctly, then slice down to what's on screen.
+            let mut full_series = indicator.series(&chronological_bars);
+            full_series.reverse();
+
+            let mut visible_series: Vec<Option<f64>> = full_series[start..end].to_vec();
+            visible_series.reverse();
+
+            let mut lines: Vec<Line> = vec![];
+            let mut x_position: f64 = bar_spacing(pitch) * 2.0;
+            let mut previous_point: Option<(f64, f64)> = None;
+
+            for value in visible_series {
+                match value {
+                    Some(v) => {
+                        let point = (x_position, (price_range.highest - v) * scaling);
+                        if let Some(previous) = previous_point {
+                            lines.push(Line::new(previous, point));
+                        }
+                        previous_point = Some(point);
+                    }
+                    None => previous_point = None
+                }
+                x_position += pitch;
+            }
+
+            (lines, *color)
+        }).collect()
+    }
+
+    /// Returns the volume histogram bars for the currently visible bars,
+    /// scaled to fill the volume panel and colored to match their candle.
+    pub(crate) fn volume_bars(&self) -> Vec<VolumeBar> {
+        let bars = self.visible_bars();
+        let max_volume = bars.iter().fold(0.0, |max, bar| if bar.volume > max { bar.volume } else { max });
+
+        let panel_top = self.price_panel_height();
+        let panel_height = self.volume_panel_height();
+        let scaling: f64 = if max_volume > 0.0 { panel_height / max_volume } else { 0.0 };
+
+        let pitch = bar_pitch(self.size, self.bars_per_view);
+        let width = bar_width(pitch);
+        let mut x_position: f64 = bar_spacing(pitch) * 2.0;
+        let mut volume_bars: Vec<VolumeBar> = vec![];
+
+        for bar in bars {
+            let bar_height = bar.volume * scaling;
+            let body = Rect::from_origin_size(
+                (x_position - width / 2.0, panel_top + (panel_height - bar_height)),
+                (width, bar_height)
+            );
+
+            let color = if bar.close >= bar.open {
+                Color::rgb8(0x38, 0xc1, 0x72)
+            } else {
+                Color::rgb8(0xdc, 0x30, 0x30)
+            };
+
+            x_position += pitch;
+
+            volume_bars.push(VolumeBar { body, color });
+        }
+
+        volume_bars
+    }
+
+    /// Builds the crosshair and its labels for the current cursor position,
+    /// or `None` if the cursor isn't over the chart or there's nothing to show.
+    fn crosshair(&self) -> Option<Crosshair> {
+        let cursor = self.cursor_pos?;
+        let bars = self.visible_bars();
+        if bars.is_empty() {
+            return None;
+        }
+
+        let pitch = bar_pitch(self.size, self.bars_per_view);
+        let start_x = bar_spacing(pitch) * 2.0;
+        let raw_index = (cursor.x - start_x) / pitch;
+        let index = (raw_index.round().max(0.0) as usize).min(bars.len() - 1);
+        let bar = bars[index].clone();
+
+        let snapped_x = start_x + index as f64 * pitch;
+        let vertical = Line::new((snapped_x, 0.0), (snapped_x, self.size.height - X_AXIS_LABELS_PADDING));
+        let horizontal = Line::new((BAR_SPACING as f64, cursor.y), (self.size.width - Y_AXIS_LABELS_PADDING, cursor.y));
+
+        let price_range = self.price_range(bars);
+        let scaling: f64 = self.price_panel_height() / price_range.range();
+        let price_at_cursor = price_range.highest - cursor.y / scaling;
+
+        let price_label = AxisLabel {
+            label: format!("{:.2}", price_at_cursor),
+            position: (self.size.width - Y_AXIS_LABELS_PADDING + 10.0, cursor.y - FONT_SIZE / 2.0)
+        };
+
+        // Keep the tooltip near the cursor's height, clamped so it stays
+        // inside the price panel instead of drawing off the top or bottom.
+        let tooltip_y = (cursor.y - 20.0).clamp(0.0, (self.price_panel_height() - FONT_SIZE).max(0.0));
+        let tooltip = AxisLabel {
+            label: format!("{}  O:{:.2} H:{:.2} L:{:.2} C:{:.2}", bar.date, bar.open, bar.high, bar.low, bar.close),
+            position: (snapped_x + 10.0, tooltip_y)
+        };
+
+        Some(Crosshair { vertical, horizontal, price_label, tooltip })
+    }
 }
 
 impl Widget<Chart> for ChartWidget {
 
-    fn event(&mut self, _ctx: &mut EventCtx, _event: &Event, _data: &mut Chart, _env: &Env) {}
+    fn event(&mut self, ctx: &mut EventCtx, event: &Event, data: &mut Chart, _env: &Env) {
+        match event {
+            Event::MouseDown(mouse) if mouse.button.is_left() => {
+                self.drag_origin = Some(mouse.pos);
+            }
+
+            Event::MouseMove(mouse) => {
+                if let Some(origin) = self.drag_origin {
+                    let pitch = bar_pitch(self.size, data.bars_per_view);
+                    let dx = mouse.pos.x - origin.x;
+                    let bar_delta = (dx / pitch) as i32;
+
+                    if bar_delta != 0 {
+                        // Dragging right pans back into history (the view_offset grows);
+                        // dragging left pans forward towards the most recent bar.
+                        data.view_offset = clamp_pan(data.view_offset, bar_delta, data.bars.len(), data.bars_per_view);
+
+                        // Only account for the bars we've already consumed, so the drag
+                        // doesn't accelerate as the mouse keeps moving.
+                        self.drag_origin = Some(Point::new(
+                            origin.x + bar_delta as f64 * pitch,
+                            mouse.pos.y
+                        ));
+                    }
+                }
+
+                self.cursor_pos = Some(mouse.pos);
+                ctx.request_paint();
+            }
+
+            Event::MouseUp(mouse) if mouse.button.is_left() => {
+                self.drag_origin = None;
+            }
+
+            Event::Wheel(mouse) => {
+                let pitch = bar_pitch(self.size, data.bars_per_view);
+                let zooming_in = mouse.wheel_delta.y < 0.0;
+
+                // Keep the bar under the cursor in roughly the same spot as we zoom.
+                let cursor_bar = (mouse.pos.x / pitch).max(0.0);
+                let cursor_ratio = cursor_bar / data.bars_per_view.max(1) as f64;
+
+                let (new_bars_per_view, new_offset) = clamp_zoom(
+                    data.bars_per_view, data.view_offset, zooming_in, cursor_ratio, data.bars.len()
+                );
+
+                if new_bars_per_view != data.bars_per_view {
+                    data.bars_per_view = new_bars_per_view;
+                    data.view_offset = new_offset;
+                    ctx.request_paint();
+                }
+            }
+
+            _ => {}
+        }
+    }
 
     fn lifecycle(
         &mut self,
-        _ctx: &mut LifeCycleCtx,
-        _event: &LifeCycle,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
         _data: &Chart,
         _env: &Env,
     ) {
+        // Druid surfaces hover/leave through hot status rather than a
+        // dedicated mouse-leave event; clear the crosshair when the cursor
+        // leaves the widget's region.
+        if let LifeCycle::HotChanged(false) = event {
+            self.cursor_pos = None;
+            ctx.request_paint();
+        }
     }
 
     fn update(&mut self, _ctx: &mut UpdateCtx, _old_data: &Chart, _data: &Chart, _env: &Env) {}
@@ -244,18 +630,24 @@ impl Widget<Chart> for ChartWidget {
         // using this, since always make sure the widget is bounded.
         // If bx.max() is used in a scrolling widget things will probably
         // not work correctly.
-        if bc.is_width_bounded() | bc.is_height_bounded() {
-            let size = Size::new(100.0, 100.0);
-            bc.constrain(size)
+        let size = if bc.is_width_bounded() | bc.is_height_bounded() {
+            bc.constrain(Size::new(100.0, 100.0))
         } else {
             bc.max()
-        }
+        };
+
+        // Keep track of the current size so `event()` can reason about bar
+        // pitch (and therefore cursor position) the same way `paint()` does.
+        self.size = size;
+        size
     }
 
     fn paint(&mut self, ctx: &mut PaintCtx, data: &Chart, _env: &Env) {
         let start_time = Instant::now();
 
-        let widget = ChartWidget::new(data, ctx.size());
+        let mut widget = ChartWidget::new(data, ctx.size());
+        widget.indicators = self.indicators.clone();
+        widget.cursor_pos = self.cursor_pos;
         ctx.stroke(widget.x_axis(), &TEXT_COLOR, 1.0);
         ctx.stroke(widget.y_axis(), &TEXT_COLOR, 1.0);
 
@@ -275,20 +667,220 @@ impl Widget<Chart> for ChartWidget {
 
         for candle in widget.candles() {
             ctx.stroke(candle.wick, &TEXT_COLOR, 1.0);
+            ctx.fill(candle.body, &candle.color);
+        }
 
-            // TODO: plot the x-axis label
-            // let layout = ctx
-            //     .text()
-            //     .new_text_layout(bar.date.day().to_string())
-            //     .font(FontFamily::SANS_SERIF, 14.0)
-            //     .text_color(TEXT_COLOR)
-            //     .build()
-            //     .unwrap();
-            // ctx.draw_text(&layout, (x_position as f64, size.height - X_AXIS_LABELS_PADDING + 5.0));
+        for label in widget.x_axis_labels() {
+            let layout = ctx
+                .text()
+                .new_text_layout(label.label)
+                .font(FontFamily::SANS_SERIF, FONT_SIZE)
+                .text_color(TEXT_COLOR)
+                .build()
+                .unwrap();
+            ctx.draw_text(&layout, label.position);
+        }
 
-            ctx.fill(candle.body, &candle.color);
+        for (lines, color) in widget.indicator_lines() {
+            for line in lines {
+                ctx.stroke(line, &color, 2.0);
+            }
+        }
+
+        for volume_bar in widget.volume_bars() {
+            ctx.fill(volume_bar.body, &volume_bar.color);
+        }
+
+        if let Some(crosshair) = widget.crosshair() {
+            let mut dashed = StrokeStyle::new();
+            dashed.set_dash_pattern([4.0, 4.0]);
+            ctx.stroke_styled(crosshair.vertical, &TEXT_COLOR, 1.0, &dashed);
+            ctx.stroke_styled(crosshair.horizontal, &TEXT_COLOR, 1.0, &dashed);
+
+            let price_layout = ctx
+                .text()
+                .new_text_layout(crosshair.price_label.label)
+                .font(FontFamily::SANS_SERIF, FONT_SIZE)
+                .text_color(TEXT_COLOR)
+                .build()
+                .unwrap();
+            ctx.draw_text(&price_layout, crosshair.price_label.position);
+
+            let tooltip_layout = ctx
+                .text()
+                .new_text_layout(crosshair.tooltip.label)
+                .font(FontFamily::SANS_SERIF, FONT_SIZE)
+                .text_color(TEXT_COLOR)
+                .build()
+                .unwrap();
+            let tooltip_size = tooltip_layout.size();
+            let tooltip_background = Rect::from_origin_size(
+                (crosshair.tooltip.position.0 - 4.0, crosshair.tooltip.position.1 - 2.0),
+                (tooltip_size.width + 8.0, tooltip_size.height + 4.0)
+            );
+            ctx.fill(tooltip_background, &Color::rgba8(0x00, 0x00, 0x00, 0xaa));
+            ctx.draw_text(&tooltip_layout, crosshair.tooltip.position);
         }
 
         println!("Total render time: {:?} milliseconds", start_time.elapsed().as_millis());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_pan_clamps_to_the_start_and_end_of_the_data() {
+        // 100 bars, 20 per view: offset can range from 0 to 80.
+        assert_eq!(clamp_pan(40, -100, 100, 20), 0);
+        assert_eq!(clamp_pan(40, 100, 100, 20), 80);
+        assert_eq!(clamp_pan(40, 5, 100, 20), 45);
+    }
+
+    #[test]
+    fn clamp_pan_has_no_room_to_pan_when_all_bars_are_already_visible() {
+        assert_eq!(clamp_pan(0, 10, 20, 20), 0);
+    }
+
+    #[test]
+    fn clamp_zoom_in_shrinks_bars_per_view_down_to_the_minimum() {
+        let (bars_per_view, _) = clamp_zoom(MIN_BARS_PER_VIEW + 2, 0, true, 0.0, 1000);
+        assert_eq!(bars_per_view, MIN_BARS_PER_VIEW);
+
+        let (bars_per_view, _) = clamp_zoom(MIN_BARS_PER_VIEW, 0, true, 0.0, 1000);
+        assert_eq!(bars_per_view, MIN_BARS_PER_VIEW);
+    }
+
+    #[test]
+    fn clamp_zoom_out_grows_bars_per_view_up_to_the_data_length() {
+        let (bars_per_view, _) = clamp_zoom(90, 0, false, 0.0, 100);
+        assert_eq!(bars_per_view, 95);
+
+        let (bars_per_view, _) = clamp_zoom(98, 0, false, 0.0, 100);
+        assert_eq!(bars_per_view, 100);
+    }
+
+    #[test]
+    fn clamp_zoom_keeps_the_bar_under_the_cursor_in_place() {
+        // Zooming out around the middle of the viewport should shift the
+        // offset back by roughly half the bars added, clamped to the data.
+        let (bars_per_view, view_offset) = clamp_zoom(40, 30, false, 0.5, 200);
+        assert_eq!(bars_per_view, 45);
+        assert_eq!(view_offset, 28);
+    }
+
+    #[test]
+    fn clamp_zoom_keeps_the_bar_under_an_off_center_cursor_in_place() {
+        // `view_offset=50, bars_per_view=10` puts bar index 59 (the oldest
+        // bar in view, `view_offset + bars_per_view - 1`) at the viewport's
+        // left/oldest edge, i.e. `cursor_ratio=0.0`. Zooming out must keep
+        // that same bar at the left edge of the new, wider viewport.
+        let (bars_per_view, view_offset) = clamp_zoom(10, 50, false, 0.0, 1000);
+        assert_eq!(bars_per_view, 15);
+        assert_eq!(view_offset, 45);
+        assert_eq!(view_offset + bars_per_view - 1, 59);
+    }
+
+    #[test]
+    fn clamp_zoom_is_a_no_op_at_the_cap() {
+        let (bars_per_view, view_offset) = clamp_zoom(100, 7, false, 0.5, 100);
+        assert_eq!(bars_per_view, 100);
+        assert_eq!(view_offset, 7);
+    }
+
+    fn bar(date: chrono::NaiveDate) -> Bar {
+        Bar { date: Arc::new(date), open: 1.0, high: 1.0, low: 1.0, close: 1.0, volume: 0.0 }
+    }
+
+    /// Builds a widget holding `ascending` (oldest-first) bars, stored the
+    /// way `self.bars` actually is (newest-first), with the whole range in
+    /// view.
+    fn widget_with_bars(ascending: Vec<Bar>, size: Size) -> ChartWidget {
+        let bars_per_view = ascending.len();
+        ChartWidget {
+            bars: Arc::new(ascending.into_iter().rev().collect()),
+            view_offset: 0,
+            bars_per_view,
+            size,
+            drag_origin: None,
+            indicators: vec![],
+            cursor_pos: None
+        }
+    }
+
+    #[test]
+    fn x_axis_labels_labels_the_first_bar_and_each_month_boundary() {
+        use chrono::{Duration, NaiveDate};
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 25).unwrap();
+        let bars: Vec<Bar> = (0..20).map(|i| bar(start + Duration::days(i))).collect();
+
+        // Narrow enough that the 20 bars are squeezed to a 3.5px pitch, so
+        // the stride is 16 bars and never fires again on its own after the
+        // February label within this fixture.
+        let widget = widget_with_bars(bars, Size::new(120.0, 400.0));
+        let labels = widget.x_axis_labels();
+
+        let texts: Vec<&str> = labels.iter().map(|l| l.label.as_str()).collect();
+        assert_eq!(texts, vec!["January 2024", "February 2024"]);
+    }
+
+    #[test]
+    fn x_axis_labels_label_text_falls_back_to_the_day_within_a_month() {
+        use chrono::NaiveDate;
+
+        let start = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let bars: Vec<Bar> = (0..5).map(|i| bar(start + chrono::Duration::days(i))).collect();
+
+        // Wide enough that the bars sit at the full 15px pitch, so a 55px
+        // label fits every 4 bars (Jan 10-14 are all in the same ISO week).
+        let widget = widget_with_bars(bars, Size::new(800.0, 400.0));
+        let labels = widget.x_axis_labels();
+
+        let texts: Vec<&str> = labels.iter().map(|l| l.label.as_str()).collect();
+        assert_eq!(texts, vec!["January 2024", "14"]);
+    }
+
+    #[test]
+    fn x_axis_labels_is_empty_with_no_bars() {
+        let widget = widget_with_bars(vec![], Size::new(800.0, 400.0));
+        assert!(widget.x_axis_labels().is_empty());
+    }
+
+    fn volume_bar(date: chrono::NaiveDate, open: f64, close: f64, volume: f64) -> Bar {
+        Bar { date: Arc::new(date), open, high: open.max(close), low: open.min(close), close, volume }
+    }
+
+    #[test]
+    fn volume_bars_scales_the_tallest_bar_to_fill_the_volume_panel() {
+        use chrono::NaiveDate;
+
+        let bars = vec![
+            volume_bar(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 10.0, 10.5, 50.0),
+            volume_bar(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), 10.5, 9.8, 100.0)
+        ];
+        let widget = widget_with_bars(bars, Size::new(800.0, 400.0));
+
+        let tallest = widget.volume_bars().iter()
+            .map(|bar| bar.body.height())
+            .fold(0.0, f64::max);
+
+        assert_eq!(tallest, widget.volume_panel_height());
+    }
+
+    #[test]
+    fn volume_bars_color_matches_the_candle_up_down_color() {
+        use chrono::NaiveDate;
+
+        let bars = vec![
+            volume_bar(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 10.0, 10.5, 10.0), // up: close >= open
+            volume_bar(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), 10.5, 9.8, 10.0)    // down: close < open
+        ];
+        let widget = widget_with_bars(bars, Size::new(800.0, 400.0));
+
+        let volume_bars = widget.volume_bars();
+        assert_eq!(volume_bars[0].color, Color::rgb8(0x38, 0xc1, 0x72));
+        assert_eq!(volume_bars[1].color, Color::rgb8(0xdc, 0x30, 0x30));
+    }
+}
@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+
+use druid::{Color, Size};
+
+use plotters::backend::{BitMapBackend, DrawingBackend, SVGBackend};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+use crate::chart_widget::ChartWidget;
+use crate::indicators::Indicator;
+use crate::Chart;
+
+const BACKGROUND_COLOR: RGBColor = RGBColor(0x15, 0x15, 0x15);
+
+impl Chart {
+    /// Renders this chart to a static image file, independent of the druid
+    /// window, so charts can be generated from scripts or a server. The
+    /// format is picked from `path`'s extension: `.png` renders a bitmap,
+    /// anything else (e.g. `.svg`) renders an SVG. `indicators` are overlaid
+    /// the same way `ChartWidget` draws them in the GUI.
+    pub fn export(&self, path: &Path, size: Size, indicators: Vec<(Arc<dyn Indicator>, Color)>) -> Result<(), Box<dyn Error>> {
+        let mut widget = ChartWidget::new(self, size);
+        for (indicator, color) in indicators {
+            widget.add_indicator(indicator, color);
+        }
+
+        let dimensions = (size.width as u32, size.height as u32);
+
+        if path.extension().and_then(|ext| ext.to_str()) == Some("png") {
+            draw(&widget, BitMapBackend::new(path, dimensions).into_drawing_area())
+        } else {
+            draw(&widget, SVGBackend::new(path, dimensions).into_drawing_area())
+        }
+    }
+}
+
+/// Draws the same axes, ticks, wicks, candle bodies, volume bars, indicator
+/// overlays and date labels as `ChartWidget::paint` (everything but the
+/// cursor-driven crosshair), onto whichever plotters backend the caller picked.
+fn draw<DB: DrawingBackend>(widget: &ChartWidget, area: DrawingArea<DB, Shift>) -> Result<(), Box<dyn Error>>
+where
+    DB::ErrorType: 'static
+{
+    area.fill(&BACKGROUND_COLOR)?;
+
+    area.draw(&PathElement::new(line_points(widget.x_axis()), &TEXT_COLOR))?;
+    area.draw(&PathElement::new(line_points(widget.y_axis()), &TEXT_COLOR))?;
+
+    for tick in widget.y_axis_ticks() {
+        area.draw(&PathElement::new(line_points(tick.tick_line), &TEXT_COLOR))?;
+        area.draw(&Text::new(
+            tick.label.label,
+            (tick.label.position.0 as i32, tick.label.position.1 as i32),
+            ("sans-serif", 14).into_font().color(&TEXT_COLOR)
+        ))?;
+    }
+
+    for candle in widget.candles() {
+        area.draw(&PathElement::new(line_points(candle.wick), &TEXT_COLOR))?;
+        area.draw(&Rectangle::new(rect_points(candle.body), rgb_color(candle.color).filled()))?;
+    }
+
+    for label in widget.x_axis_labels() {
+        area.draw(&Text::new(
+            label.label,
+            (label.position.0 as i32, label.position.1 as i32),
+            ("sans-serif", 14).into_font().color(&TEXT_COLOR)
+        ))?;
+    }
+
+    for (lines, color) in widget.indicator_lines() {
+        let stroke_color = rgb_color(color);
+        for line in lines {
+            area.draw(&PathElement::new(line_points(line), &stroke_color))?;
+        }
+    }
+
+    for volume_bar in widget.volume_bars() {
+        area.draw(&Rectangle::new(rect_points(volume_bar.body), rgb_color(volume_bar.color).filled()))?;
+    }
+
+    area.present()?;
+    Ok(())
+}
+
+const TEXT_COLOR: RGBColor = RGBColor(0xef, 0xf8, 0xff);
+
+fn line_points(line: druid::kurbo::Line) -> [(i32, i32); 2] {
+    [(line.p0.x as i32, line.p0.y as i32), (line.p1.x as i32, line.p1.y as i32)]
+}
+
+fn rect_points(rect: druid::Rect) -> [(i32, i32); 2] {
+    [(rect.x0 as i32, rect.y0 as i32), (rect.x1 as i32, rect.y1 as i32)]
+}
+
+fn rgb_color(color: druid::Color) -> RGBColor {
+    let (r, g, b, _a) = color.as_rgba8();
+    RGBColor(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bar;
+    use chrono::{Duration, NaiveDate};
+    use std::fs;
+
+    fn sample_chart() -> Chart {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let bars: Vec<Bar> = (0..10).map(|i| Bar {
+            date: Arc::new(start + Duration::days(i)),
+            open: 10.0 + i as f64,
+            high: 11.0 + i as f64,
+            low: 9.0 + i as f64,
+            close: 10.5 + i as f64,
+            volume: 100.0 + i as f64
+        }).collect();
+
+        Chart { bars: Arc::new(bars), view_offset: 0, bars_per_view: 10 }
+    }
+
+    /// A throwaway path under the system temp dir, namespaced by pid and test
+    /// name so parallel test runs don't collide.
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("finviewer_export_test_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn export_writes_a_non_empty_svg_file() {
+        let path = temp_path("smoke.svg");
+
+        sample_chart().export(&path, Size::new(400.0, 300.0), vec![]).unwrap();
+
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn export_writes_a_non_empty_png_file() {
+        let path = temp_path("smoke.png");
+
+        sample_chart().export(&path, Size::new(400.0, 300.0), vec![]).unwrap();
+
+        assert!(fs::metadata(&path).unwrap().len() > 0);
+        fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn export_dispatches_to_the_backend_matching_the_path_extension() {
+        let svg_path = temp_path("dispatch.svg");
+        let png_path = temp_path("dispatch.png");
+
+        sample_chart().export(&svg_path, Size::new(200.0, 150.0), vec![]).unwrap();
+        sample_chart().export(&png_path, Size::new(200.0, 150.0), vec![]).unwrap();
+
+        // SVG is text starting with an XML/SVG header, PNG is binary starting
+        // with its magic bytes -- if the extension dispatch picked the wrong
+        // backend for either path, one of these would fail.
+        let svg_bytes = fs::read(&svg_path).unwrap();
+        let png_bytes = fs::read(&png_path).unwrap();
+        assert!(svg_bytes.starts_with(b"<?xml") || svg_bytes.starts_with(b"<svg"));
+        assert_eq!(&png_bytes[0..8], b"\x89PNG\r\n\x1a\n");
+
+        fs::remove_file(&svg_path).ok();
+        fs::remove_file(&png_path).ok();
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Bar;
+
+/// A price-derived line series that can be overlaid on top of the candles,
+/// e.g. a moving average. One value is returned per bar, aligned by index,
+/// with `None` for bars that fall inside the indicator's warm-up period.
+///
+/// `bars` must be in chronological (oldest-first) order; callers holding
+/// newest-first data (e.g. `self.bars` on `ChartWidget`) need to reverse it
+/// before calling `series` and reverse the result back to line the values
+/// up with their original indices.
+pub trait Indicator {
+    fn series(&self, bars: &[Bar]) -> Vec<Option<f64>>;
+}
+
+/// Simple moving average: the mean of the last `period` closes.
+pub struct Sma {
+    pub period: usize
+}
+
+impl Indicator for Sma {
+    fn series(&self, bars: &[Bar]) -> Vec<Option<f64>> {
+        bars.iter().enumerate().map(|(i, _)| {
+            if i + 1 < self.period {
+                return None;
+            }
+            let window = &bars[i + 1 - self.period..=i];
+            let sum: f64 = window.iter().map(|bar| bar.close).sum();
+            Some(sum / self.period as f64)
+        }).collect()
+    }
+}
+
+/// Exponential moving average, seeded with the SMA of the first `period`
+/// closes: `ema_t = alpha * close_t + (1 - alpha) * ema_{t-1}`.
+pub struct Ema {
+    pub period: usize
+}
+
+impl Indicator for Ema {
+    fn series(&self, bars: &[Bar]) -> Vec<Option<f64>> {
+        let mut series = vec![None; bars.len()];
+
+        if bars.len() < self.period {
+            return series;
+        }
+
+        let alpha = 2.0 / (self.period as f64 + 1.0);
+        let seed: f64 = bars[0..self.period].iter().map(|bar| bar.close).sum::<f64>() / self.period as f64;
+
+        series[self.period - 1] = Some(seed);
+        let mut previous = seed;
+
+        for i in self.period..bars.len() {
+            let value = alpha * bars[i].close + (1.0 - alpha) * previous;
+            series[i] = Some(value);
+            previous = value;
+        }
+
+        series
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::sync::Arc;
+
+    fn bar(day: u32, close: f64) -> Bar {
+        Bar {
+            date: Arc::new(NaiveDate::from_ymd_opt(2024, 1, day).unwrap()),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 0.0
+        }
+    }
+
+    #[test]
+    fn sma_averages_the_trailing_window() {
+        let bars = vec![bar(1, 1.0), bar(2, 2.0), bar(3, 3.0), bar(4, 4.0), bar(5, 5.0)];
+
+        let series = Sma { period: 3 }.series(&bars);
+
+        assert_eq!(series, vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+    }
+
+    #[test]
+    fn ema_seeds_with_the_sma_then_recurses_forward_in_time() {
+        let bars = vec![bar(1, 1.0), bar(2, 2.0), bar(3, 3.0), bar(4, 10.0)];
+
+        let series = Ema { period: 3 }.series(&bars);
+
+        let seed = (1.0 + 2.0 + 3.0) / 3.0;
+        let alpha = 2.0 / 4.0;
+        let next = alpha * 10.0 + (1.0 - alpha) * seed;
+
+        assert_eq!(series, vec![None, None, Some(seed), Some(next)]);
+    }
+}
@@ -1,14 +1,6 @@
-use std::sync::Arc;
+pub mod types;
+pub mod chart_widget;
+pub mod indicators;
+pub mod export;
 
-use druid::{Data, Lens};
-use chrono::NaiveDate;
-
-
-#[derive(Clone, Debug, Lens, Data)]
-pub struct Bar {
-    pub date: Arc<NaiveDate>, // wrap this is Arc because Data trait is implemented for that.
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64
-}
+pub use types::{Bar, Chart};
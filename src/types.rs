@@ -10,19 +10,33 @@ pub struct Bar {
     pub open: f64,
     pub high: f64,
     pub low: f64,
-    pub close: f64
+    pub close: f64,
+    pub volume: f64
 }
 
 
+/// Default number of bars shown in the viewport before the user zooms.
+pub const DEFAULT_BARS_PER_VIEW: usize = 50;
+
 #[derive(Debug, Clone, Data, Lens)]
 pub struct Chart {
-    pub bars: Arc<Vec<Bar>>
+    pub bars: Arc<Vec<Bar>>,
+
+    /// How many bars back from the most recent one the viewport starts.
+    /// 0 means the view is scrolled all the way to the right edge.
+    pub view_offset: usize,
+
+    /// How many bars are shown in the viewport at once. Panning translates
+    /// `view_offset`, zooming grows/shrinks this.
+    pub bars_per_view: usize
 }
 
 impl Chart {
     pub fn new() -> Self {
         Self {
-            bars: Arc::new(vec![])
+            bars: Arc::new(vec![]),
+            view_offset: 0,
+            bars_per_view: DEFAULT_BARS_PER_VIEW
         }
     }
 }